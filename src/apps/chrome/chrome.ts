@@ -4,8 +4,16 @@ import type { App } from "../../service";
 type AppState = {
   urls: string[];
   chromeWindowId: string;
+  // Tabs with no url, or whose url starts with one of these, are not saved on sync
+  skipUrlPrefixes?: string[];
 };
 
+const defaultSkipUrlPrefixes = [
+  "about:blank",
+  "chrome://newtab",
+  "devtools://",
+];
+
 type TabsMessage = Record<string, string[]>;
 
 const port = 3149;
@@ -57,7 +65,15 @@ export async function launchApp(app: App<AppState>) {
   const oldTabs = await getTabs();
 
   const command = ["chromium", "--new-window", ...app.data.urls];
-  Bun.spawnSync(command);
+  const { success, exitCode, stderr } = Bun.spawnSync(command, {
+    stderr: "pipe",
+  });
+
+  if (!success) {
+    throw new Error(
+      `Chromium exited with code ${exitCode}: ${stderr.toString().trim()}`,
+    );
+  }
 
   const newTabs = await getTabs();
 
@@ -88,7 +104,11 @@ export async function syncApp(app: App<AppState>) {
   const tabDict = await getTabs();
   const myTabs = tabDict[app.data.chromeWindowId] ?? [];
   console.log("Got tabs", myTabs);
-  app.data.urls = myTabs;
+  const skipUrlPrefixes = app.data.skipUrlPrefixes ?? defaultSkipUrlPrefixes;
+  app.data.urls = myTabs.filter(
+    (url) =>
+      !!url && !skipUrlPrefixes.some((prefix) => url.startsWith(prefix)),
+  );
 }
 
 export async function close() {
@@ -5,8 +5,8 @@ const command = process.argv[2];
 
 function printHelp() {
   console.log("Commands:");
-  console.log("  open <workspace>");
-  console.log("  list-workspaces");
+  console.log("  open <workspace> [--force]");
+  console.log("  list-workspaces [--verbose]");
   console.log("  read-state");
   console.log("  read-config");
   console.log("  sync");
@@ -18,12 +18,26 @@ function printHelp() {
 switch (command) {
   case "open":
     const workspaceName = process.argv[3];
-    await Service.selectWorkspace(workspaceName);
+    await Service.selectWorkspace(
+      workspaceName,
+      process.argv.includes("--force"),
+    );
     break;
-  case "list-workspaces":
+  case "list-workspaces": {
+    if (process.argv.includes("--verbose")) {
+      const summaries = await Service.getWorkspaceSummaries();
+      for (const summary of summaries) {
+        const marker = summary.isOpened ? "*" : " ";
+        console.log(
+          `${marker} ${summary.name} (${summary.i3WorkspaceCount} i3 workspaces, ${summary.appCount} apps)`,
+        );
+      }
+      break;
+    }
     const worksapces = await Service.getAllWorkspaces();
     console.log(worksapces.join("\n"));
     break;
+  }
   case "read-state":
     const state = await Service.getFromFs();
     console.log(JSON.stringify(state, null, 2));
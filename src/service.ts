@@ -59,30 +59,74 @@ export async function getAllWorkspaces() {
   return workspaces.map((w) => w.name);
 }
 
+export async function getWorkspaceSummaries() {
+  const workspaces = await getFromFs();
+  return workspaces.map((w) => ({
+    name: w.name,
+    isOpened: w.isOpened,
+    i3WorkspaceCount: new Set(w.apps.map((a) => a.i3Workspace)).size,
+    appCount: w.apps.length,
+  }));
+}
+
 export async function getOpenedWorkspace() {
   const workspaces = await getFromFs();
   return workspaces.find((w) => w.isOpened);
 }
 
-export async function selectWorkspace(workspaceName: string) {
+// Exact matches win, then prefix matches, then substring matches
+function findWorkspaceCandidates(workspaces: Workspace[], name: string) {
+  if (!name) {
+    return [];
+  }
+
+  const exact = workspaces.filter((w) => w.name === name);
+  if (exact.length > 0) {
+    return exact;
+  }
+
+  const prefixed = workspaces.filter((w) => w.name.startsWith(name));
+  if (prefixed.length > 0) {
+    return prefixed;
+  }
+
+  return workspaces.filter((w) => w.name.includes(name));
+}
+
+export async function selectWorkspace(workspaceName: string, force = false) {
   console.log("Selecting workspace", workspaceName);
   const workspaces = await getFromFs();
 
-  const workspace = workspaces.find((w) => w.name === workspaceName);
+  const candidates = findWorkspaceCandidates(workspaces, workspaceName);
+
+  if (candidates.length > 1) {
+    console.error(
+      `Workspace ${workspaceName} is ambiguous:`,
+      candidates.map((w) => w.name).join(", "),
+    );
+    process.exitCode = 1;
+    return;
+  }
+
+  const workspace = candidates[0];
 
   if (!workspace) {
     console.error(`Workspace ${workspaceName} not found`);
+    process.exitCode = 1;
     return;
   }
 
   const currentlyOpenedWorkspace = workspaces.find((w) => w.isOpened);
 
-  if (currentlyOpenedWorkspace) {
+  if (currentlyOpenedWorkspace === workspace && !force) {
+    // Nothing foreign should be on screen, so only reopen missing apps
+    console.log("Workspace already opened, skipping stow");
+  } else if (currentlyOpenedWorkspace) {
     currentlyOpenedWorkspace.isOpened = false;
 
     for (const app of currentlyOpenedWorkspace.apps) {
       if (!isOpened(app)) {
-        return;
+        continue;
       }
       I3Service.moveWindowToScratchPad(app.i3WindowId);
     }
@@ -92,7 +136,13 @@ export async function selectWorkspace(workspaceName: string) {
 
   for (const app of workspace.apps) {
     let openedApp: OpenedApp<any>;
-    openedApp = await openApp(app);
+    try {
+      openedApp = await openApp(app);
+    } catch (e) {
+      // Keep going so one broken app doesn't block the rest of the workspace
+      console.error(`⚠️ Failed to open app ${app.name}:`, e);
+      continue;
+    }
 
     await new Promise((resolve) => setTimeout(resolve, 100));
 
@@ -117,7 +167,7 @@ async function openApp<T>(app: App<T>): Promise<OpenedApp<T>> {
       return app;
     } else {
       console.log("App is opened but window is not found. Removing id.");
-      (app as any).i3WindowId = undefined;
+      delete (app as any).i3WindowId;
     }
   }
 
@@ -183,12 +233,22 @@ export async function daemon() {
   }
 }
 
-export async function newWorkspace(name: string) {
+export async function newWorkspace(rawName: string) {
+  // The name comes straight from argv, so it may be missing
+  const name = rawName?.trim();
+
+  if (!name) {
+    console.error("Workspace name cannot be empty");
+    process.exitCode = 1;
+    return;
+  }
+
   const workspaces = await getFromFs();
 
   // Check if workspace already exists
   if (workspaces.find((w) => w.name === name)) {
     console.error(`Workspace ${name} already exists`);
+    process.exitCode = 1;
     return;
   }
 